@@ -1,5 +1,9 @@
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub};
 use rust_decimal::Decimal;
+pub use rust_decimal::RoundingStrategy;
 use serde::{Deserialize, Deserializer, Serialize};
+#[cfg(feature = "serde-str")]
+use serde::Serializer;
 use std::{
     fmt,
     iter::{Product, Sum},
@@ -10,9 +14,10 @@ use std::{
 #[cfg(feature = "borsh")]
 use borsh::{BorshDeserialize, BorshSerialize};
 
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Serialize, Clone, Copy, Default)]
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Clone, Copy, Default)]
 #[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
-#[serde(transparent)]
+#[cfg_attr(not(feature = "serde-str"), derive(Serialize))]
+#[cfg_attr(not(feature = "serde-str"), serde(transparent))]
 #[cfg_attr(feature = "sqlx", derive(sqlx::Type), sqlx(transparent))]
 pub struct NormDecimal(Decimal);
 
@@ -35,6 +40,96 @@ impl NormDecimal {
     pub fn min(self, other: impl Into<Decimal>) -> Self {
         Self::from(other.into().min(self.0))
     }
+
+    /// Builds a `NormDecimal` from an integer atomic amount at a fixed number of
+    /// fractional digits, i.e. `value / 10^decimal_places`.
+    pub fn from_atomics(value: impl Into<i128>, decimal_places: u32) -> Result<Self, rust_decimal::Error> {
+        Decimal::try_from_i128_with_scale(value.into(), decimal_places).map(Self::from)
+    }
+
+    /// Inverse of [`NormDecimal::from_atomics`]: rescales to exactly `decimal_places`
+    /// fractional digits and returns the raw integer mantissa, or `None` if that would
+    /// overflow `i128` or require dropping non-zero digits.
+    pub fn to_atomics(&self, decimal_places: u32) -> Option<i128> {
+        let scale = self.0.scale();
+        let mantissa = self.0.mantissa();
+        if decimal_places >= scale {
+            let factor = 10i128.checked_pow(decimal_places - scale)?;
+            mantissa.checked_mul(factor)
+        } else {
+            let factor = 10i128.checked_pow(scale - decimal_places)?;
+            (mantissa % factor == 0).then(|| mantissa / factor)
+        }
+    }
+
+    /// `x` hundredths, e.g. `NormDecimal::percent(50)` is `0.5`.
+    ///
+    /// Not a `const fn`: `rust_decimal` has no const value+scale constructor, and
+    /// normalization itself (`Decimal::normalize`) isn't `const`, so a const
+    /// constructor isn't achievable on top of it.
+    pub fn percent(x: i64) -> Self {
+        Self::from(Decimal::new(x, 2))
+    }
+
+    /// `x` thousandths, e.g. `NormDecimal::permille(5)` is `0.005`.
+    ///
+    /// Not a `const fn`, for the same reason as [`NormDecimal::percent`].
+    pub fn permille(x: i64) -> Self {
+        Self::from(Decimal::new(x, 3))
+    }
+
+    /// `x` ten-thousandths, e.g. `NormDecimal::basis_points(25)` is `0.0025`.
+    ///
+    /// Not a `const fn`, for the same reason as [`NormDecimal::percent`].
+    pub fn basis_points(x: i64) -> Self {
+        Self::from(Decimal::new(x, 4))
+    }
+
+    /// Rounds to `dp` decimal places using the default (banker's) rounding strategy.
+    pub fn round_dp(&self, dp: u32) -> NormDecimal {
+        Self::from(self.0.round_dp(dp))
+    }
+
+    /// Rounds to `dp` decimal places using an explicit [`RoundingStrategy`].
+    pub fn round_dp_with(&self, dp: u32, strategy: RoundingStrategy) -> NormDecimal {
+        Self::from(self.0.round_dp_with_strategy(dp, strategy))
+    }
+
+    /// Rounds to `dp` decimal places and returns the integer count of minor units
+    /// (e.g. cents at `dp == 2`), or `None` if that would overflow `i64`.
+    pub fn to_minor_units(&self, dp: u32) -> Option<i64> {
+        let mut rounded = self.0.round_dp(dp);
+        rounded.rescale(dp);
+        i64::try_from(rounded.mantissa()).ok()
+    }
+
+    /// Returns `self` padded/truncated to exactly `scale` fractional digits, for
+    /// display purposes. The result is a plain `Decimal` rather than a `NormDecimal`
+    /// because it deliberately carries trailing zeros, which would violate the
+    /// always-normalized invariant `NormDecimal` otherwise upholds; `self` is
+    /// untouched.
+    pub fn with_scale(&self, scale: u32) -> Decimal {
+        let mut value = self.0.round_dp_with_strategy(scale, RoundingStrategy::ToZero);
+        value.rescale(scale);
+        value
+    }
+
+    /// Parses scientific notation such as `"1.5e-8"` or `"3.2E3"`, which plain
+    /// [`FromStr`] does not accept.
+    pub fn from_scientific(s: &str) -> Result<Self, rust_decimal::Error> {
+        Decimal::from_scientific(s).map(Self::from)
+    }
+
+    /// Computes `self * mul + add` on the raw underlying values, normalizing only
+    /// the final result instead of the intermediate product. `Decimal::normalize`
+    /// never changes a value, only its stored scale, so for a single call this is
+    /// equivalent to `self * mul + add` computed through the regular operators; the
+    /// win is skipping the intermediate normalization step itself, which matters
+    /// when `mul_add` is chained in a loop (e.g. a running weighted sum) and the
+    /// per-term normalization would otherwise add up.
+    pub fn mul_add(self, mul: impl Into<NormDecimal>, add: impl Into<NormDecimal>) -> NormDecimal {
+        Self::from(self.0 * mul.into().0 + add.into().0)
+    }
 }
 
 impl FromStr for NormDecimal {
@@ -77,6 +172,7 @@ impl fmt::Display for NormDecimal {
     }
 }
 
+#[cfg(not(feature = "serde-str"))]
 impl<'de> Deserialize<'de> for NormDecimal {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -86,6 +182,30 @@ impl<'de> Deserialize<'de> for NormDecimal {
     }
 }
 
+/// Under the `serde-str` feature, `NormDecimal` serializes to and parses from its
+/// canonical decimal string form, so formats that would otherwise round-trip it
+/// through a float (notably JSON and its JS consumers) preserve full precision.
+#[cfg(feature = "serde-str")]
+impl Serialize for NormDecimal {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde-str")]
+impl<'de> Deserialize<'de> for NormDecimal {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = <std::borrow::Cow<str> as Deserialize>::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
 macro_rules! forward_from_impl {
     ($($typ:ident),+) => {
         $(impl From<$typ> for NormDecimal {
@@ -206,6 +326,58 @@ where
     }
 }
 
+impl NormDecimal {
+    pub fn checked_add(self, rhs: impl Into<NormDecimal>) -> Option<Self> {
+        self.0.checked_add(rhs.into().0).map(Self::from)
+    }
+
+    pub fn checked_sub(self, rhs: impl Into<NormDecimal>) -> Option<Self> {
+        self.0.checked_sub(rhs.into().0).map(Self::from)
+    }
+
+    pub fn checked_mul(self, rhs: impl Into<NormDecimal>) -> Option<Self> {
+        self.0.checked_mul(rhs.into().0).map(Self::from)
+    }
+
+    pub fn checked_div(self, rhs: impl Into<NormDecimal>) -> Option<Self> {
+        self.0.checked_div(rhs.into().0).map(Self::from)
+    }
+
+    pub fn checked_rem(self, rhs: impl Into<NormDecimal>) -> Option<Self> {
+        self.0.checked_rem(rhs.into().0).map(Self::from)
+    }
+}
+
+impl CheckedAdd for NormDecimal {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Self::checked_add(*self, *v)
+    }
+}
+
+impl CheckedSub for NormDecimal {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        Self::checked_sub(*self, *v)
+    }
+}
+
+impl CheckedMul for NormDecimal {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Self::checked_mul(*self, *v)
+    }
+}
+
+impl CheckedDiv for NormDecimal {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        Self::checked_div(*self, *v)
+    }
+}
+
+impl CheckedRem for NormDecimal {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        Self::checked_rem(*self, *v)
+    }
+}
+
 impl Sum for NormDecimal {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         iter.fold(NormDecimal::ZERO, Add::add)
@@ -217,3 +389,130 @@ impl Product for NormDecimal {
         iter.fold(NormDecimal::ONE, Mul::mul)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_div_by_zero_is_none() {
+        assert_eq!(NormDecimal::ONE.checked_div(NormDecimal::ZERO), None);
+    }
+
+    #[test]
+    fn checked_rem_by_zero_is_none() {
+        assert_eq!(NormDecimal::ONE.checked_rem(NormDecimal::ZERO), None);
+    }
+
+    #[test]
+    fn checked_add_overflow_is_none() {
+        let max = NormDecimal::from(Decimal::MAX);
+        assert_eq!(max.checked_add(NormDecimal::ONE), None);
+    }
+
+    #[test]
+    fn checked_mul_overflow_is_none() {
+        let max = NormDecimal::from(Decimal::MAX);
+        assert_eq!(max.checked_mul(NormDecimal::from(2u64)), None);
+    }
+
+    #[test]
+    fn checked_ops_succeed_on_the_happy_path() {
+        let a = NormDecimal::from(6u64);
+        let b = NormDecimal::from(3u64);
+        assert_eq!(a.checked_add(b), Some(NormDecimal::from(9u64)));
+        assert_eq!(a.checked_sub(b), Some(NormDecimal::from(3u64)));
+        assert_eq!(a.checked_mul(b), Some(NormDecimal::from(18u64)));
+        assert_eq!(a.checked_div(b), Some(NormDecimal::from(2u64)));
+        assert_eq!(a.checked_rem(b), Some(NormDecimal::ZERO));
+    }
+
+    #[test]
+    fn from_atomics_round_trips_through_to_atomics() {
+        let value = NormDecimal::from_atomics(1_500_000_000_000_000_000i128, 18).unwrap();
+        assert_eq!(value.to_atomics(18), Some(1_500_000_000_000_000_000));
+    }
+
+    #[test]
+    fn to_atomics_rejects_dropping_non_zero_digits() {
+        let value = NormDecimal::from_str("1.23").unwrap();
+        assert_eq!(value.to_atomics(1), None);
+        assert_eq!(value.to_atomics(2), Some(123));
+    }
+
+    #[test]
+    fn to_atomics_rejects_i128_overflow() {
+        let value = NormDecimal::from(Decimal::MAX);
+        assert_eq!(value.to_atomics(28), None);
+    }
+
+    #[test]
+    fn from_atomics_rejects_scale_over_max() {
+        assert!(NormDecimal::from_atomics(1i128, 29).is_err());
+    }
+
+    #[test]
+    fn round_dp_with_selects_strategy() {
+        let value = NormDecimal::from_str("2.5").unwrap();
+        assert_eq!(
+            value.round_dp_with(0, RoundingStrategy::MidpointAwayFromZero),
+            NormDecimal::from(3u64)
+        );
+        assert_eq!(value.round_dp_with(0, RoundingStrategy::ToZero), NormDecimal::from(2u64));
+    }
+
+    #[test]
+    fn to_minor_units_converts_to_cents() {
+        let value = NormDecimal::from_str("19.999").unwrap();
+        assert_eq!(value.to_minor_units(2), Some(2000));
+    }
+
+    #[test]
+    fn to_minor_units_rejects_i64_overflow() {
+        let value = NormDecimal::from(Decimal::MAX);
+        assert_eq!(value.to_minor_units(0), None);
+    }
+
+    #[test]
+    fn mul_add_matches_the_chained_computation() {
+        let a = NormDecimal::from_str("0.5").unwrap();
+        let b = NormDecimal::from_str("0.4").unwrap();
+        let c = NormDecimal::from_str("1.1").unwrap();
+
+        // `a * b` leaves a trailing zero (0.20) that the regular `Mul` impl
+        // normalizes away (to 0.2) before the add; `mul_add` skips that
+        // intermediate step. `Decimal::normalize` is value-preserving, so both
+        // forms must still agree on the final, fully-normalized result.
+        assert_eq!(a.mul_add(b, c), a * b + c);
+        assert_eq!(a.mul_add(b, c), NormDecimal::from_str("1.3").unwrap());
+    }
+
+    #[test]
+    fn with_scale_pads_for_display_without_changing_self() {
+        let value = NormDecimal::ONE;
+        assert_eq!(value.with_scale(3).to_string(), "1.000");
+        assert_eq!(value, NormDecimal::ONE);
+    }
+
+    #[test]
+    fn from_scientific_parses_small_and_large_magnitudes() {
+        assert_eq!(
+            NormDecimal::from_scientific("1.5e-8").unwrap(),
+            NormDecimal::from_str("0.000000015").unwrap()
+        );
+        assert_eq!(
+            NormDecimal::from_scientific("3.2E3").unwrap(),
+            NormDecimal::from_str("3200").unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde-str")]
+    fn serde_str_round_trips_through_json() {
+        let value = NormDecimal::from_scientific("1.5e-8").unwrap();
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, "\"0.000000015\"");
+        let parsed: NormDecimal = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, value);
+    }
+}